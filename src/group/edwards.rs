@@ -0,0 +1,166 @@
+//! A twisted-Edwards curve backend modeled on babyjubjub-rs: the same
+//! equality-of-discrete-logs proof as the mod-`p` backend, but on a
+//! ~256-bit curve embedded in the BN254 scalar field, which is far
+//! cheaper to exponentiate on than a 2048-bit RFC 5114 group.
+
+use num_bigint::BigUint;
+
+use super::Group;
+
+/// A point on the curve `a*x^2 + y^2 = 1 + d*x^2*y^2 (mod p)`, in affine
+/// coordinates. The identity is the point `(0, 1)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EdwardsPoint {
+    pub x: BigUint,
+    pub y: BigUint,
+}
+
+/// Parameters of the twisted-Edwards curve: the base field modulus `p`,
+/// curve coefficients `a` and `d`, and the order `n` of the prime-order
+/// subgroup generated by the embedded base point.
+#[derive(Clone)]
+pub struct EdwardsGroup {
+    pub p: BigUint,
+    pub a: BigUint,
+    pub d: BigUint,
+    pub n: BigUint,
+}
+
+impl EdwardsGroup {
+    pub fn new(p: BigUint, a: BigUint, d: BigUint, n: BigUint) -> Self {
+        EdwardsGroup { p, a, d, n }
+    }
+
+    /// BabyJubJub, the curve used throughout babyjubjub-rs: embedded in the
+    /// BN254 scalar field so its arithmetic composes with circuits over
+    /// that field.
+    pub fn baby_jubjub() -> (Self, EdwardsPoint) {
+        let p = BigUint::parse_bytes(
+            b"21888242871839275222246405745257275088548364400416034343698204186575808495617",
+            10,
+        )
+        .unwrap();
+        let a = BigUint::from(168700u32);
+        let d = BigUint::from(168696u32);
+        let n = BigUint::parse_bytes(
+            b"2736030358979909402780800718157159386076813972158567259200215660948447373041",
+            10,
+        )
+        .unwrap();
+
+        // babyjubjub-rs calls this point `B8`: the curve's generator `G`
+        // has order `8n` (the cofactor is 8), so proofs use `B8 = 8*G`,
+        // which generates the prime-order-`n` subgroup instead.
+        let base = EdwardsPoint {
+            x: BigUint::parse_bytes(
+                b"5299619240641551281634865583518297030282874472190772894086521144482721001553",
+                10,
+            )
+            .unwrap(),
+            y: BigUint::parse_bytes(
+                b"16950150798460657717958625567821834550301663161624707787222815936182638968203",
+                10,
+            )
+            .unwrap(),
+        };
+
+        (EdwardsGroup::new(p, a, d, n), base)
+    }
+
+    fn add_mod_p(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + b) % &self.p
+    }
+
+    fn sub_mod_p(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p - (b % &self.p)) % &self.p
+    }
+
+    fn mul_mod_p(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.p
+    }
+
+    /// Modular inverse via Fermat's little theorem: `a^(p-2) mod p`, valid
+    /// since `p` is prime.
+    fn inv_mod_p(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.p - BigUint::from(2u32)), &self.p)
+    }
+
+    fn div_mod_p(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        self.mul_mod_p(a, &self.inv_mod_p(b))
+    }
+}
+
+impl Group for EdwardsGroup {
+    type Element = EdwardsPoint;
+
+    /// Unified twisted-Edwards point addition:
+    /// `x3 = (x1*y2 + y1*x2) / (1 + d*x1*x2*y1*y2)`
+    /// `y3 = (y1*y2 - a*x1*x2) / (1 - d*x1*x2*y1*y2)`
+    fn combine(&self, p1: &EdwardsPoint, p2: &EdwardsPoint) -> EdwardsPoint {
+        let x1y2 = self.mul_mod_p(&p1.x, &p2.y);
+        let y1x2 = self.mul_mod_p(&p1.y, &p2.x);
+        let y1y2 = self.mul_mod_p(&p1.y, &p2.y);
+        let x1x2 = self.mul_mod_p(&p1.x, &p2.x);
+        let d_x1x2y1y2 = self.mul_mod_p(&self.d, &self.mul_mod_p(&x1x2, &y1y2));
+
+        let x3_num = self.add_mod_p(&x1y2, &y1x2);
+        let x3_den = self.add_mod_p(&BigUint::from(1u32), &d_x1x2y1y2);
+
+        let y3_num = self.sub_mod_p(&y1y2, &self.mul_mod_p(&self.a, &x1x2));
+        let y3_den = self.sub_mod_p(&BigUint::from(1u32), &d_x1x2y1y2);
+
+        EdwardsPoint {
+            x: self.div_mod_p(&x3_num, &x3_den),
+            y: self.div_mod_p(&y3_num, &y3_den),
+        }
+    }
+
+    /// Double-and-add scalar multiplication built on top of [`combine`].
+    fn scalar_mul(&self, element: &EdwardsPoint, scalar: &BigUint) -> EdwardsPoint {
+        let mut result = self.identity();
+        let mut addend = element.clone();
+        let mut remaining = scalar.clone();
+
+        while remaining > BigUint::from(0u32) {
+            if &remaining % 2u32 == BigUint::from(1u32) {
+                result = self.combine(&result, &addend);
+            }
+            addend = self.combine(&addend, &addend);
+            remaining /= 2u32;
+        }
+
+        result
+    }
+
+    fn identity(&self) -> EdwardsPoint {
+        EdwardsPoint {
+            x: BigUint::from(0u32),
+            y: BigUint::from(1u32),
+        }
+    }
+
+    fn order(&self) -> BigUint {
+        self.n.clone()
+    }
+
+    fn element_to_bytes(&self, element: &EdwardsPoint) -> Vec<u8> {
+        let coord_len = self.element_byte_len() / 2;
+        let mut bytes = pad_to(element.x.to_bytes_be(), coord_len);
+        bytes.extend(pad_to(element.y.to_bytes_be(), coord_len));
+        bytes
+    }
+
+    fn element_byte_len(&self) -> usize {
+        2 * self.p.bits().div_ceil(8) as usize
+    }
+
+    fn domain_bytes(&self) -> Vec<u8> {
+        self.p.to_bytes_be()
+    }
+}
+
+fn pad_to(bytes: Vec<u8>, width: usize) -> Vec<u8> {
+    let mut padded = vec![0u8; width.saturating_sub(bytes.len())];
+    padded.extend(bytes);
+    padded
+}
@@ -0,0 +1,48 @@
+//! Abstraction over the cyclic group the Chaum-Pedersen protocol runs in,
+//! so [`crate::ZKP`] doesn't have to care whether proofs are done with
+//! modular exponentiation or elliptic-curve point arithmetic.
+
+pub mod edwards;
+pub mod modp;
+
+use num_bigint::BigUint;
+
+/// A cyclic group of known order in which equality-of-discrete-logs proofs
+/// can be carried out. Scalars (exponents / point multipliers) are always
+/// plain `BigUint`s reduced mod [`Group::order`]; only the group element
+/// representation and its operations vary between backends.
+pub trait Group {
+    /// An element of the group (e.g. a residue mod `p`, or a curve point).
+    type Element: Clone + PartialEq;
+
+    /// The group operation, written additively as `a + b` for curves and
+    /// multiplicatively as `a * b` for the mod-`p` backend.
+    fn combine(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+
+    /// `element` combined with itself `scalar` times, i.e. `scalar * element`
+    /// (curve backend) or `element^scalar` (mod-`p` backend).
+    fn scalar_mul(&self, element: &Self::Element, scalar: &BigUint) -> Self::Element;
+
+    /// The group's identity element.
+    fn identity(&self) -> Self::Element;
+
+    /// The order `q` of the (sub)group, i.e. the modulus scalars live in.
+    fn order(&self) -> BigUint;
+
+    /// Fixed-width big-endian encoding of an element, used to build the
+    /// Fiat-Shamir transcript in [`crate::ZKP::prove_noninteractive`].
+    fn element_to_bytes(&self, element: &Self::Element) -> Vec<u8>;
+
+    /// The width, in bytes, that [`Group::element_to_bytes`] always pads
+    /// its output to. [`crate::ZKP::verify`] relies on this to compare
+    /// encodings of equal length in constant time.
+    fn element_byte_len(&self) -> usize;
+
+    /// A big-endian encoding of whatever modulus this group's elements
+    /// live in (`p` for the mod-`p` backend, the curve's field prime for
+    /// the Edwards backend). [`crate::ZKP::prove_noninteractive`] folds
+    /// this into the Fiat-Shamir transcript so two groups that happen to
+    /// share the same generators and order, but differ in their modulus,
+    /// can't produce colliding (and therefore replayable) transcripts.
+    fn domain_bytes(&self) -> Vec<u8>;
+}
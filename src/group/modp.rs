@@ -0,0 +1,165 @@
+//! The original backend: the multiplicative group of residues mod a prime
+//! `p = k*q + 1`, restricted to the order-`q` subgroup generated by `alpha`.
+
+use num_bigint::{BigUint, RandBigInt};
+
+use super::Group;
+
+/// Parameters of a mod-`p` Schnorr-style group: elements are `BigUint`s in
+/// `[0, p)`, the group operation is multiplication mod `p`, and the
+/// subgroup used for proofs has prime order `q`.
+#[derive(Clone)]
+pub struct ModPGroup {
+    pub p: BigUint,
+    pub q: BigUint,
+}
+
+impl ModPGroup {
+    pub fn new(p: BigUint, q: BigUint) -> Self {
+        ModPGroup { p, q }
+    }
+
+    /// Generate a fresh `(ModPGroup, alpha, beta)` at the requested security
+    /// level instead of relying on the hardcoded RFC 5114 constants: find a
+    /// `q_bits`-bit prime `q`, a prime `p = k*q + 1`, and a generator
+    /// `alpha` of the order-`q` subgroup, then derive `beta` the same way
+    /// [`crate::ZKP::get_constants`] does.
+    pub fn generate_parameters(q_bits: u64) -> (ModPGroup, BigUint, BigUint) {
+        let q = random_prime(q_bits);
+        let p = find_safe_prime(&q);
+
+        let alpha = find_subgroup_generator(&p, &q);
+        let i = rand::thread_rng().gen_biguint_below(&q);
+        let beta = alpha.modpow(&i, &p);
+
+        (ModPGroup::new(p, q), alpha, beta)
+    }
+}
+
+/// Miller-Rabin primality test: write `n - 1 = 2^s * d`, then for each of
+/// `rounds` random bases `a` square `a^d mod n` looking for a witness that
+/// `n` is composite. Returns `true` only when no witness is found.
+fn is_probably_prime(n: &BigUint, rounds: u32) -> bool {
+    let zero = BigUint::from(0u32);
+    let one = BigUint::from(1u32);
+    let two = BigUint::from(2u32);
+    let three = BigUint::from(3u32);
+
+    if *n < two {
+        return false;
+    }
+    if *n == two || *n == three {
+        return true;
+    }
+    if n % &two == zero {
+        return false;
+    }
+
+    let n_minus_1 = n - &one;
+    let mut d = n_minus_1.clone();
+    let mut s = 0u32;
+    while &d % &two == zero {
+        d /= &two;
+        s += 1;
+    }
+
+    let mut rng = rand::thread_rng();
+    'rounds: for _ in 0..rounds {
+        let a = rng.gen_biguint_range(&two, &(n - &two));
+        let mut x = a.modpow(&d, n);
+        if x == one || x == n_minus_1 {
+            continue;
+        }
+
+        for _ in 0..s - 1 {
+            x = x.modpow(&two, n);
+            if x == n_minus_1 {
+                continue 'rounds;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Find a prime of exactly `bits` bits by repeatedly sampling random odd
+/// candidates with the top bit set and Miller-Rabin testing them.
+fn random_prime(bits: u64) -> BigUint {
+    let mut rng = rand::thread_rng();
+    loop {
+        let mut candidate = rng.gen_biguint(bits);
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(0, true);
+
+        if is_probably_prime(&candidate, 40) {
+            return candidate;
+        }
+    }
+}
+
+/// Find a prime `p = k*q + 1` by incrementing `k` from 2 and primality
+/// testing each candidate, so the order-`q` subgroup of `Z*_p` exists.
+fn find_safe_prime(q: &BigUint) -> BigUint {
+    let mut k = BigUint::from(2u32);
+    loop {
+        let p = &k * q + BigUint::from(1u32);
+        if is_probably_prime(&p, 40) {
+            return p;
+        }
+        k += BigUint::from(1u32);
+    }
+}
+
+/// Derive a generator of the order-`q` subgroup of `Z*_p`: pick random `h`
+/// in `[2, p-2]`, set `alpha = h^((p-1)/q) mod p`, and reject `h` if that
+/// lands on the identity.
+fn find_subgroup_generator(p: &BigUint, q: &BigUint) -> BigUint {
+    let exponent = (p - BigUint::from(1u32)) / q;
+    let mut rng = rand::thread_rng();
+
+    loop {
+        let h = rng.gen_biguint_range(&BigUint::from(2u32), &(p - BigUint::from(1u32)));
+        let alpha = h.modpow(&exponent, p);
+        if alpha != BigUint::from(1u32) {
+            return alpha;
+        }
+    }
+}
+
+impl Group for ModPGroup {
+    type Element = BigUint;
+
+    fn combine(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a * b) % &self.p
+    }
+
+    fn scalar_mul(&self, element: &BigUint, scalar: &BigUint) -> BigUint {
+        element.modpow(scalar, &self.p)
+    }
+
+    fn identity(&self) -> BigUint {
+        BigUint::from(1u32)
+    }
+
+    fn order(&self) -> BigUint {
+        self.q.clone()
+    }
+
+    fn element_to_bytes(&self, element: &BigUint) -> Vec<u8> {
+        let width = self.element_byte_len();
+        let bytes = element.to_bytes_be();
+        let mut padded = vec![0u8; width.saturating_sub(bytes.len())];
+        padded.extend(bytes);
+        padded
+    }
+
+    fn element_byte_len(&self) -> usize {
+        self.p.bits().div_ceil(8) as usize
+    }
+
+    fn domain_bytes(&self) -> Vec<u8> {
+        self.p.to_bytes_be()
+    }
+}
@@ -1,42 +1,225 @@
+mod group;
+mod wire;
+
+pub use group::edwards::{EdwardsGroup, EdwardsPoint};
+pub use group::modp::ModPGroup;
+pub use group::Group;
+pub use wire::{CodecError, Parameters, Proof};
+
+use blake2::{Blake2b512, Digest};
 use num_bigint::{BigUint, RandBigInt};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Prepended to every Fiat-Shamir transcript so a non-interactive proof
+/// generated for this crate can't be replayed against another protocol
+/// that happens to hash the same public values.
+const NIZK_DOMAIN: &[u8] = b"zkp-chaum-pedersen/fiat-shamir-v1";
+
+/// Wraps a secret `BigUint` (the prover's `x` or nonce `k`) so its digit
+/// limbs are overwritten when it's dropped. `BigUint` itself can't
+/// implement `zeroize::Zeroize` (it's a foreign type), and reassigning a
+/// `BigUint` field only drops the old heap buffer without scrubbing it, so
+/// instead we hold the secret as its own `Vec<u32>` digits — which *can*
+/// be zeroized — and rebuild a `BigUint` from them on demand.
+///
+/// This only protects the long-lived container: each [`Secret::expose`]
+/// call allocates a plain, non-zeroizing `BigUint` for whatever arithmetic
+/// needs it, and that copy is dropped (not scrubbed) like any other value.
+/// Callers should call `expose` once per scope and reuse the binding,
+/// rather than calling it again for every operand.
+struct Secret {
+    digits: Vec<u32>,
+}
+
+impl Secret {
+    fn new(value: BigUint) -> Self {
+        Secret {
+            digits: value.to_u32_digits(),
+        }
+    }
 
-pub struct ZKP {
-    p: BigUint,
-    q: BigUint,
-    alpha: BigUint,
-    beta: BigUint,
+    fn expose(&self) -> BigUint {
+        BigUint::new(self.digits.clone())
+    }
 }
 
-impl ZKP {
-    /// output = n^exp mod p
-    pub fn exponentiate(n: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
-        // return will be interpreted on the line which have no semicolon at the end
-        n.modpow(exponent, modulus)
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.digits.zeroize();
+    }
+}
+
+/// A Chaum-Pedersen equality-of-discrete-logs proof system over some
+/// cyclic group `G`: given `y1 = x·g1` and `y2 = x·g2`, the prover
+/// convinces the verifier it knows `x` without revealing it.
+pub struct ZKP<G: Group> {
+    group: G,
+    g1: G::Element,
+    g2: G::Element,
+}
+
+/// A non-interactive proof produced by [`ZKP::prove_noninteractive`]: the
+/// commitments `(r1, r2)`, the challenge `c` derived from them via
+/// Fiat-Shamir, and the response `s`.
+pub struct NoninteractiveProof<G: Group> {
+    pub r1: G::Element,
+    pub r2: G::Element,
+    pub c: BigUint,
+    pub s: BigUint,
+}
+
+/// Sample a uniformly random `BigUint` in `[0, bound)`. Kept as a free
+/// function rather than a method on `ZKP<G>` so generic code (any `G`) can
+/// use it without forcing a particular `G` into scope; [`ZKP::generate_random_below`]
+/// is the public, mod-`p`-specific entry point callers should reach for.
+fn random_below(bound: &BigUint) -> BigUint {
+    rand::thread_rng().gen_biguint_below(bound)
+}
+
+impl<G: Group> ZKP<G> {
+    /// Build a proof system over `group` using `g1`/`g2` as the two
+    /// generators the equality-of-discrete-logs proof is defined against.
+    pub fn new(group: G, g1: G::Element, g2: G::Element) -> Self {
+        ZKP { group, g1, g2 }
     }
 
     /// output = s = k - c * x mod q
+    ///
+    /// The `c * x` product is held in a [`Secret`], whose digits are zeroized
+    /// when this function returns. The temporary `BigUint` the product is
+    /// first computed into, and the copy returned by `expose()`, are plain
+    /// values and are dropped without being scrubbed. `expose()` is called
+    /// once and reused below instead of once per operand, so only one such
+    /// extra copy of the product is made.
     pub fn solve(&self, k: &BigUint, c: &BigUint, x: &BigUint) -> BigUint {
-        if *k >= c * x {
-            return (k - c * x).modpow(&BigUint::from(1u32), &self.q);
+        let q = self.group.order();
+        let cx = Secret::new(c * x);
+        let cx_val = cx.expose();
+
+        if *k >= cx_val {
+            return (k - &cx_val).modpow(&BigUint::from(1u32), &q);
         }
 
-        &self.q - (c * x -k).modpow(&BigUint::from(1u32), &self.q)
+        &q - (&cx_val - k).modpow(&BigUint::from(1u32), &q)
+    }
+
+    /// cond1: r1 == g1^s * y1^c   (additive notation: r1 == s·g1 + c·Y1)
+    /// cond2: r2 == g2^s * y2^c   (additive notation: r2 == s·g2 + c·Y2)
+    ///
+    /// Both equalities are checked by comparing fixed-width encodings with
+    /// `subtle`'s constant-time equality so the accept/reject decision
+    /// doesn't branch on secret-dependent timing.
+    pub fn verify(
+        &self,
+        r1: &G::Element,
+        r2: &G::Element,
+        y1: &G::Element,
+        y2: &G::Element,
+        c: &BigUint,
+        s: &BigUint,
+    ) -> bool {
+        let expected_r1 = self.group.combine(&self.group.scalar_mul(&self.g1, s), &self.group.scalar_mul(y1, c));
+        let expected_r2 = self.group.combine(&self.group.scalar_mul(&self.g2, s), &self.group.scalar_mul(y2, c));
+
+        let cond1 = self
+            .group
+            .element_to_bytes(r1)
+            .ct_eq(&self.group.element_to_bytes(&expected_r1));
+        let cond2 = self
+            .group
+            .element_to_bytes(r2)
+            .ct_eq(&self.group.element_to_bytes(&expected_r2));
+
+        (cond1 & cond2).into()
     }
 
-    /// cond1: r1 = alpha^s * y1^c
-    /// cond2: r2 = beta^s * y2^c
-    pub fn verify(&self, r1: &BigUint, r2: &BigUint, y1: &BigUint, y2: &BigUint, c: &BigUint, s: &BigUint) -> bool {
-        let cond1 = *r1 == (&self.alpha.modpow(s, &self.p) * y1.modpow(c, &self.p)).modpow(&BigUint::from(1u32), &self.p);
-        let cond2 = *r2 == (&self.beta.modpow(s, &self.p) * y2.modpow(c, &self.p)).modpow(&BigUint::from(1u32), &self.p);
+    /// Derive the Fiat-Shamir challenge
+    /// `c = H(domain ‖ g1 ‖ g2 ‖ modulus ‖ q ‖ y1 ‖ y2 ‖ r1 ‖ r2) mod q`
+    /// by hashing the public transcript with Blake2b, the same construction
+    /// babyjubjub-rs uses to turn its Pedersen commitments non-interactive.
+    /// `modulus` ([`Group::domain_bytes`]) binds the transcript to the
+    /// group's `p` (or field prime, for the Edwards backend), so two groups
+    /// that share generators and order but differ in modulus can't produce
+    /// colliding (and therefore cross-replayable) transcripts.
+    fn hash_challenge(&self, y1: &G::Element, y2: &G::Element, r1: &G::Element, r2: &G::Element) -> BigUint {
+        let mut hasher = Blake2b512::new();
+        hasher.update(NIZK_DOMAIN);
+        hasher.update(self.group.element_to_bytes(&self.g1));
+        hasher.update(self.group.element_to_bytes(&self.g2));
+        hasher.update(self.group.domain_bytes());
+        hasher.update(self.group.order().to_bytes_be());
+        for element in [y1, y2, r1, r2] {
+            hasher.update(self.group.element_to_bytes(element));
+        }
 
-        cond1 && cond2
+        BigUint::from_bytes_be(&hasher.finalize()) % self.group.order()
     }
 
-    pub fn generate_random_below(bound: &BigUint) -> BigUint {
-        let mut rng = rand::thread_rng(); // might be a let mutable to regenerate a new random number each time this fn is called
+    /// Non-interactive prover: commits to a fresh random `k`, derives the
+    /// challenge from the transcript instead of waiting on the verifier,
+    /// and returns everything the verifier needs to check the proof.
+    pub fn prove_noninteractive(&self, x: &BigUint, y1: &G::Element, y2: &G::Element) -> NoninteractiveProof<G> {
+        let k = Secret::new(random_below(&self.group.order()));
+        let x = Secret::new(x.clone());
+        // Expose each secret once and reuse the binding, rather than
+        // re-exposing per use and leaving extra un-scrubbed copies around.
+        let k_val = k.expose();
+        let x_val = x.expose();
+
+        let r1 = self.group.scalar_mul(&self.g1, &k_val);
+        let r2 = self.group.scalar_mul(&self.g2, &k_val);
 
-        // the random generator number should be below the parameter (in test eg: q) because it must be a number in the range of the group
-        rng.gen_biguint_below(bound)
+        let c = self.hash_challenge(y1, y2, &r1, &r2);
+        let s = self.solve(&k_val, &c, &x_val);
+
+        NoninteractiveProof { r1, r2, c, s }
+    }
+
+    /// Non-interactive verifier: recomputes the challenge from the same
+    /// transcript the prover used and rejects outright if it doesn't match
+    /// the one embedded in the proof, then falls back to the usual checks.
+    pub fn verify_noninteractive(&self, y1: &G::Element, y2: &G::Element, proof: &NoninteractiveProof<G>) -> bool {
+        let expected_c = self.hash_challenge(y1, y2, &proof.r1, &proof.r2);
+        if expected_c != proof.c {
+            return false;
+        }
+
+        self.verify(&proof.r1, &proof.r2, y1, y2, &proof.c, &proof.s)
+    }
+}
+
+impl ZKP<ModPGroup> {
+    /// Build the proof system over the classic mod-`p` backend from the
+    /// `(alpha, beta, p, q)` tuple returned by [`ZKP::get_constants`].
+    pub fn from_modp_constants(alpha: BigUint, beta: BigUint, p: BigUint, q: BigUint) -> Self {
+        ZKP::new(ModPGroup::new(p, q), alpha, beta)
+    }
+
+    /// Generate fresh mod-`p` parameters at the requested security level
+    /// (`q_bits` bits for the subgroup order) instead of using the
+    /// hardcoded RFC 5114 constants, and return a ready-to-use proof system.
+    pub fn generate_parameters(q_bits: u64) -> Self {
+        let (group, alpha, beta) = ModPGroup::generate_parameters(q_bits);
+        ZKP::new(group, alpha, beta)
+    }
+
+    /// output = n^exp mod p
+    ///
+    /// Defined only for the mod-`p` backend (not `impl<G: Group> ZKP<G>`) so
+    /// pre-existing callers can keep writing `ZKP::exponentiate(...)` without
+    /// a turbofish: `G` is uniquely determined as `ModPGroup` since that's
+    /// the only impl offering this method.
+    pub fn exponentiate(n: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+        // return will be interpreted on the line which have no semicolon at the end
+        n.modpow(exponent, modulus)
+    }
+
+    /// Same reasoning as [`ZKP::exponentiate`]: kept on `ZKP<ModPGroup>`
+    /// specifically so `ZKP::generate_random_below(...)` keeps resolving
+    /// without a turbofish for existing mod-`p` callers.
+    pub fn generate_random_below(bound: &BigUint) -> BigUint {
+        random_below(bound)
     }
 
     pub fn get_constants() -> (BigUint, BigUint, BigUint, BigUint) {
@@ -50,12 +233,25 @@ impl ZKP {
         );
 
         // beta = alpha^i is also a generator
-        let beta = alpha.modpow(&ZKP::generate_random_below(&q), &p);
+        let beta = alpha.modpow(&random_below(&q), &p);
 
         (alpha, beta, p, q)
     }
 }
 
+impl ZKP<EdwardsGroup> {
+    /// Build the proof system over BabyJubJub, using the embedded base
+    /// point as `g1` and a random multiple of it as `g2` (mirroring how
+    /// the mod-`p` backend derives `beta` from `alpha`).
+    pub fn from_baby_jubjub() -> Self {
+        let (curve, base) = EdwardsGroup::baby_jubjub();
+        let i = random_below(&curve.order());
+        let g2 = curve.scalar_mul(&base, &i);
+
+        ZKP::new(curve, base, g2)
+    }
+}
+
 #[cfg(test)] // needed for rust to interpret this as a test
 mod test {
     // include all pub fn in lib.rs
@@ -70,13 +266,13 @@ mod test {
         let beta = BigUint::from(9u32); // generator
         let p = BigUint::from(23u32); // prime number
         let q = BigUint::from(11u32); // group order
-        let zkp = ZKP { p: p.clone(), q: q.clone(), alpha: alpha.clone(), beta: beta.clone() }; // clone is needed because BigUint is not copy
+        let zkp = ZKP::from_modp_constants(alpha.clone(), beta.clone(), p.clone(), q.clone());
 
         let x = BigUint::from(6u32); // secret
         let k = BigUint::from(7u32); // random
-        
+
         let c = BigUint::from(4u32); // challenge
-        
+
         // y1 = alpha^x mod p
         // y2 = beta^x mod p
         let y1 = ZKP::exponentiate(&alpha, &x, &p);
@@ -98,7 +294,7 @@ mod test {
 
         assert_eq!(s, BigUint::from(5u32)); // s = 7 - 4 * 6 mod 11 = 5
 
-        let result = zkp.verify(&r1, &r2, &y1, &y2,  &c, &s); // r1 = alpha^s * y1^c mod p
+        let result = zkp.verify(&r1, &r2, &y1, &y2, &c, &s); // r1 = alpha^s * y1^c mod p
         assert!(result); // should be true
 
         // fake secret
@@ -109,7 +305,6 @@ mod test {
         assert!(!result); // should be false
     }
 
-    
     #[test]
     fn test_example_with_random_numbers() {
         // alpha = 4, beta = 9, p = 23, q = 11
@@ -119,13 +314,13 @@ mod test {
         let beta = BigUint::from(9u32); // generator
         let p = BigUint::from(23u32); // prime number
         let q = BigUint::from(11u32); // group order
-        let zkp = ZKP { p: p.clone(), q: q.clone(), alpha: alpha.clone(), beta: beta.clone() };
+        let zkp = ZKP::from_modp_constants(alpha.clone(), beta.clone(), p.clone(), q.clone());
 
         let x = BigUint::from(6u32); // secret
         let k = ZKP::generate_random_below(&q); // random
-        
+
         let c = ZKP::generate_random_below(&q); // challenge
-        
+
         // y1 = alpha^x mod p
         // y2 = beta^x mod p
         let y1 = ZKP::exponentiate(&alpha, &x, &p);
@@ -141,30 +336,30 @@ mod test {
 
         let s = zkp.solve(&k, &c, &x); // s = k - c * x mod q
 
-        let result = zkp.verify(&r1, &r2, &y1, &y2,&c, &s); // r1 = alpha^s * y1^c mod p
+        let result = zkp.verify(&r1, &r2, &y1, &y2, &c, &s); // r1 = alpha^s * y1^c mod p
         assert!(result); // should be true
     }
-    
+
     #[test]
     // cf this ressource https://www.rfc-editor.org/rfc/rfc5114#page-4
     fn test_1024_bits_constants() {
         let p = BigUint::from_bytes_be(&hex::decode("B10B8F96A080E01DDE92DE5EAE5D54EC52C99FBCFB06A3C69A6A9DCA52D23B616073E28675A23D189838EF1E2EE652C013ECB4AEA906112324975C3CD49B83BFACCBDD7D90C4BD7098488E9C219A73724EFFD6FAE5644738FAA31A4FF55BCCC0A151AF5F0DC8B4BD45BF37DF365C1A65E68CFDA76D4DA708DF1FB2BC2E4A4371").unwrap());
         let q = BigUint::from_bytes_be(&hex::decode("F518AA8781A8DF278ABA4E7D64B7CB9D49462353").unwrap());
-        
+
         let alpha = BigUint::from_bytes_be(
             &hex::decode("A4D1CBD5C3FD34126765A442EFB99905F8104DD258AC507FD6406CFF14266D31266FEA1E5C41564B777E690F5504F213160217B4B01B886A5E91547F9E2749F4D7FBD7D3B9A92EE1909D0D2263F80A76A6A24C087A091F531DBF0A0169B6A28AD662A4D18E73AFA32D779D5918D08BC8858F4DCEF97C2A24855E6EEB22B3B2E5").unwrap(),
         );
 
         // beta = alpha^i(elevated to any number) is also a generator
         let beta = alpha.modpow(&ZKP::generate_random_below(&q), &p);
- 
-        let zkp = ZKP { p: p.clone(), q: q.clone(), alpha: alpha.clone(), beta: beta.clone() };
+
+        let zkp = ZKP::from_modp_constants(alpha.clone(), beta.clone(), p.clone(), q.clone());
 
         let x = ZKP::generate_random_below(&q); // secret
         let k = ZKP::generate_random_below(&q); // random
-        
+
         let c = ZKP::generate_random_below(&q); // challenge
-        
+
         // y1 = alpha^x mod p
         // y2 = beta^x mod p
         let y1 = ZKP::exponentiate(&alpha, &x, &p);
@@ -177,11 +372,10 @@ mod test {
 
         let s = zkp.solve(&k, &c, &x); // s = k - c * x mod q
 
-        let result = zkp.verify(&r1, &r2, &y1, &y2,&c, &s); // r1 = alpha^s * y1^c mod p
+        let result = zkp.verify(&r1, &r2, &y1, &y2, &c, &s); // r1 = alpha^s * y1^c mod p
         assert!(result); // should be true
     }
 
-
     #[test]
     fn test_2048_bits_constants() {
         let p = BigUint::from_bytes_be(&hex::decode("AD107E1E9123A9D0D660FAA79559C51FA20D64E5683B9FD1B54B1597B61D0A75E6FA141DF95A56DBAF9A3C407BA1DF15EB3D688A309C180E1DE6B85A1274A0A66D3F8152AD6AC2129037C9EDEFDA4DF8D91E8FEF55B7394B7AD5B7D0B6C12207C9F98D11ED34DBF6C6BA0B2C8BBC27BE6A00E0A0B9C49708B3BF8A317091883681286130BC8985DB1602E714415D9330278273C7DE31EFDC7310F7121FD5A07415987D9ADC0A486DCDF93ACC44328387315D75E198C641A480CD86A1B9E587E8BE60E69CC928B2B9C52172E413042E9B23F10B0E16E79763C9B53DCF4BA80A29E3FB73C16B8E75B97EF363E2FFA31F71CF9DE5384E71B81C0AC4DFFE0C10E64F").unwrap());
@@ -196,7 +390,7 @@ mod test {
         // beta = alpha^i is also a generator
         let beta = alpha.modpow(&ZKP::generate_random_below(&q), &p);
 
-        let zkp = ZKP { p: p.clone(), q: q.clone(), alpha: alpha.clone(), beta: beta.clone()};
+        let zkp = ZKP::from_modp_constants(alpha.clone(), beta.clone(), p.clone(), q.clone());
 
         let x = ZKP::generate_random_below(&q);
         let k = ZKP::generate_random_below(&q);
@@ -214,4 +408,93 @@ mod test {
         let result = zkp.verify(&r1, &r2, &y1, &y2, &c, &s);
         assert!(result);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_noninteractive_1024_bits_constants() {
+        let (alpha, beta, p, q) = ZKP::get_constants();
+        let zkp = ZKP::from_modp_constants(alpha.clone(), beta.clone(), p.clone(), q.clone());
+
+        let x = ZKP::generate_random_below(&q); // secret
+
+        let y1 = ZKP::exponentiate(&alpha, &x, &p);
+        let y2 = ZKP::exponentiate(&beta, &x, &p);
+
+        let proof = zkp.prove_noninteractive(&x, &y1, &y2);
+        assert!(zkp.verify_noninteractive(&y1, &y2, &proof));
+
+        // tampering with the response should break verification
+        let mut forged = proof;
+        forged.s += BigUint::from(1u32);
+        assert!(!zkp.verify_noninteractive(&y1, &y2, &forged));
+    }
+
+    #[test]
+    fn test_noninteractive_2048_bits_constants() {
+        let p = BigUint::from_bytes_be(&hex::decode("AD107E1E9123A9D0D660FAA79559C51FA20D64E5683B9FD1B54B1597B61D0A75E6FA141DF95A56DBAF9A3C407BA1DF15EB3D688A309C180E1DE6B85A1274A0A66D3F8152AD6AC2129037C9EDEFDA4DF8D91E8FEF55B7394B7AD5B7D0B6C12207C9F98D11ED34DBF6C6BA0B2C8BBC27BE6A00E0A0B9C49708B3BF8A317091883681286130BC8985DB1602E714415D9330278273C7DE31EFDC7310F7121FD5A07415987D9ADC0A486DCDF93ACC44328387315D75E198C641A480CD86A1B9E587E8BE60E69CC928B2B9C52172E413042E9B23F10B0E16E79763C9B53DCF4BA80A29E3FB73C16B8E75B97EF363E2FFA31F71CF9DE5384E71B81C0AC4DFFE0C10E64F").unwrap());
+        let q = BigUint::from_bytes_be(
+            &hex::decode("801C0D34C58D93FE997177101F80535A4738CEBCBF389A99B36371EB").unwrap(),
+        );
+
+        let alpha = BigUint::from_bytes_be(
+            &hex::decode("AC4032EF4F2D9AE39DF30B5C8FFDAC506CDEBE7B89998CAF74866A08CFE4FFE3A6824A4E10B9A6F0DD921F01A70C4AFAAB739D7700C29F52C57DB17C620A8652BE5E9001A8D66AD7C17669101999024AF4D027275AC1348BB8A762D0521BC98AE247150422EA1ED409939D54DA7460CDB5F6C6B250717CBEF180EB34118E98D119529A45D6F834566E3025E316A330EFBB77A86F0C1AB15B051AE3D428C8F8ACB70A8137150B8EEB10E183EDD19963DDD9E263E4770589EF6AA21E7F5F2FF381B539CCE3409D13CD566AFBB48D6C019181E1BCFE94B30269EDFE72FE9B6AA4BD7B5A0F1C71CFFF4C19C418E1F6EC017981BC087F2A7065B384B890D3191F2BFA").unwrap(),
+        );
+
+        let beta = alpha.modpow(&ZKP::generate_random_below(&q), &p);
+
+        let zkp = ZKP::from_modp_constants(alpha.clone(), beta.clone(), p.clone(), q.clone());
+
+        let x = ZKP::generate_random_below(&q);
+
+        let y1 = ZKP::exponentiate(&alpha, &x, &p);
+        let y2 = ZKP::exponentiate(&beta, &x, &p);
+
+        let proof = zkp.prove_noninteractive(&x, &y1, &y2);
+        assert!(zkp.verify_noninteractive(&y1, &y2, &proof));
+    }
+
+    #[test]
+    fn test_baby_jubjub_equality_of_discrete_logs() {
+        let zkp = ZKP::from_baby_jubjub();
+
+        let x = random_below(&zkp.group.order()); // secret
+        let k = random_below(&zkp.group.order()); // random
+        let c = random_below(&zkp.group.order()); // challenge
+
+        // y1 = x·g1, y2 = x·g2
+        let y1 = zkp.group.scalar_mul(&zkp.g1, &x);
+        let y2 = zkp.group.scalar_mul(&zkp.g2, &x);
+
+        let r1 = zkp.group.scalar_mul(&zkp.g1, &k);
+        let r2 = zkp.group.scalar_mul(&zkp.g2, &k);
+
+        let s = zkp.solve(&k, &c, &x);
+
+        let result = zkp.verify(&r1, &r2, &y1, &y2, &c, &s);
+        assert!(result);
+
+        // fake secret
+        let x_fake = &x + BigUint::from(1u32);
+        let s_fake = zkp.solve(&k, &c, &x_fake);
+        assert!(!zkp.verify(&r1, &r2, &y1, &y2, &c, &s_fake));
+    }
+
+    #[test]
+    fn test_generate_parameters_end_to_end() {
+        // small bit length so the test stays fast
+        let zkp = ZKP::generate_parameters(32);
+
+        let x = ZKP::generate_random_below(&zkp.group.order()); // secret
+        let k = ZKP::generate_random_below(&zkp.group.order()); // random
+        let c = ZKP::generate_random_below(&zkp.group.order()); // challenge
+
+        let y1 = zkp.group.scalar_mul(&zkp.g1, &x);
+        let y2 = zkp.group.scalar_mul(&zkp.g2, &x);
+
+        let r1 = zkp.group.scalar_mul(&zkp.g1, &k);
+        let r2 = zkp.group.scalar_mul(&zkp.g2, &k);
+
+        let s = zkp.solve(&k, &c, &x);
+
+        assert!(zkp.verify(&r1, &r2, &y1, &y2, &c, &s));
+    }
+}
@@ -0,0 +1,213 @@
+//! Compact, length-prefixed big-endian encoding for parameters and proofs,
+//! so a prover and verifier can exchange them over the wire instead of
+//! hardcoding hex literals in-process. The framing mirrors babyjubjub-rs's
+//! point compress/decompress: each field is a 4-byte big-endian length
+//! followed by that many big-endian bytes.
+
+use num_bigint::BigUint;
+
+use crate::{ModPGroup, ZKP};
+
+/// A mod-`p` parameter set: the prime `p`, the subgroup order `q`, and the
+/// two generators `alpha`/`beta`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Parameters {
+    pub p: BigUint,
+    pub q: BigUint,
+    pub alpha: BigUint,
+    pub beta: BigUint,
+}
+
+/// A Chaum-Pedersen proof: the commitments `r1`/`r2`, the challenge `c`,
+/// and the response `s`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Proof {
+    pub r1: BigUint,
+    pub r2: BigUint,
+    pub c: BigUint,
+    pub s: BigUint,
+}
+
+/// Errors returned while decoding a [`Parameters`] or [`Proof`] from bytes.
+#[derive(Debug, PartialEq)]
+pub enum CodecError {
+    /// The byte slice ended before all fields could be read.
+    Truncated,
+    /// A decoded field element isn't reduced mod its group's `p` or `q`.
+    FieldOutOfRange,
+}
+
+fn write_field(buf: &mut Vec<u8>, n: &BigUint) {
+    let bytes = n.to_bytes_be();
+    buf.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    buf.extend_from_slice(&bytes);
+}
+
+fn read_field(bytes: &mut &[u8]) -> Result<BigUint, CodecError> {
+    if bytes.len() < 4 {
+        return Err(CodecError::Truncated);
+    }
+    let (len_bytes, rest) = bytes.split_at(4);
+    let len = u32::from_be_bytes(len_bytes.try_into().unwrap()) as usize;
+
+    if rest.len() < len {
+        return Err(CodecError::Truncated);
+    }
+    let (field, rest) = rest.split_at(len);
+    *bytes = rest;
+
+    Ok(BigUint::from_bytes_be(field))
+}
+
+impl Parameters {
+    pub fn from_zkp(zkp: &ZKP<ModPGroup>) -> Self {
+        Parameters {
+            p: zkp.group.p.clone(),
+            q: zkp.group.q.clone(),
+            alpha: zkp.g1.clone(),
+            beta: zkp.g2.clone(),
+        }
+    }
+
+    pub fn into_zkp(self) -> ZKP<ModPGroup> {
+        ZKP::from_modp_constants(self.alpha, self.beta, self.p, self.q)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_field(&mut buf, &self.p);
+        write_field(&mut buf, &self.q);
+        write_field(&mut buf, &self.alpha);
+        write_field(&mut buf, &self.beta);
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CodecError> {
+        let mut cursor = bytes;
+        let p = read_field(&mut cursor)?;
+        let q = read_field(&mut cursor)?;
+        let alpha = read_field(&mut cursor)?;
+        let beta = read_field(&mut cursor)?;
+
+        if alpha >= p || beta >= p {
+            return Err(CodecError::FieldOutOfRange);
+        }
+
+        Ok(Parameters { p, q, alpha, beta })
+    }
+}
+
+impl Proof {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        write_field(&mut buf, &self.r1);
+        write_field(&mut buf, &self.r2);
+        write_field(&mut buf, &self.c);
+        write_field(&mut buf, &self.s);
+        buf
+    }
+
+    /// Decode a proof, rejecting any field that isn't reduced mod the
+    /// `parameters` it's meant to be checked against.
+    pub fn from_bytes(bytes: &[u8], parameters: &Parameters) -> Result<Self, CodecError> {
+        let mut cursor = bytes;
+        let r1 = read_field(&mut cursor)?;
+        let r2 = read_field(&mut cursor)?;
+        let c = read_field(&mut cursor)?;
+        let s = read_field(&mut cursor)?;
+
+        if r1 >= parameters.p || r2 >= parameters.p || c >= parameters.q || s >= parameters.q {
+            return Err(CodecError::FieldOutOfRange);
+        }
+
+        Ok(Proof { r1, r2, c, s })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Group;
+
+    #[test]
+    fn test_parameters_round_trip() {
+        let zkp = ZKP::generate_parameters(32);
+        let parameters = Parameters::from_zkp(&zkp);
+
+        let bytes = parameters.to_bytes();
+        let decoded = Parameters::from_bytes(&bytes).unwrap();
+
+        assert_eq!(parameters, decoded);
+    }
+
+    #[test]
+    fn test_proof_round_trip() {
+        let zkp = ZKP::generate_parameters(32);
+        let parameters = Parameters::from_zkp(&zkp);
+
+        let x = ZKP::generate_random_below(&parameters.q);
+        let y1 = zkp.group.scalar_mul(&zkp.g1, &x);
+        let y2 = zkp.group.scalar_mul(&zkp.g2, &x);
+
+        let proof = zkp.prove_noninteractive(&x, &y1, &y2);
+        let proof = Proof {
+            r1: proof.r1,
+            r2: proof.r2,
+            c: proof.c,
+            s: proof.s,
+        };
+
+        let bytes = proof.to_bytes();
+        let decoded = Proof::from_bytes(&bytes, &parameters).unwrap();
+
+        assert_eq!(proof, decoded);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_truncated_input() {
+        let zkp = ZKP::generate_parameters(32);
+        let parameters = Parameters::from_zkp(&zkp);
+        let bytes = parameters.to_bytes();
+
+        let truncated = &bytes[..bytes.len() - 1];
+        assert_eq!(Parameters::from_bytes(truncated), Err(CodecError::Truncated));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_oversized_field() {
+        let zkp = ZKP::generate_parameters(32);
+        let parameters = Parameters::from_zkp(&zkp);
+
+        // a proof whose response `s` is not reduced mod `q` must be rejected
+        let oversized_s = &parameters.q * BigUint::from(2u32);
+        let proof = Proof {
+            r1: parameters.alpha.clone(),
+            r2: parameters.beta.clone(),
+            c: BigUint::from(1u32),
+            s: oversized_s,
+        };
+
+        let bytes = proof.to_bytes();
+        assert_eq!(Proof::from_bytes(&bytes, &parameters), Err(CodecError::FieldOutOfRange));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_oversized_commitment() {
+        let zkp = ZKP::generate_parameters(32);
+        let parameters = Parameters::from_zkp(&zkp);
+
+        // a proof whose commitment `r1` is not reduced mod `p` must be rejected
+        let oversized_r1 = &parameters.p * BigUint::from(2u32);
+        let proof = Proof {
+            r1: oversized_r1,
+            r2: parameters.beta.clone(),
+            c: BigUint::from(1u32),
+            s: BigUint::from(1u32),
+        };
+
+        let bytes = proof.to_bytes();
+        assert_eq!(Proof::from_bytes(&bytes, &parameters), Err(CodecError::FieldOutOfRange));
+    }
+}